@@ -1,72 +1,582 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 #[cfg(feature = "global_alloc")]
 extern crate alloc;
 
 #[cfg(feature = "global_alloc")]
 use core::alloc::{GlobalAlloc, Layout};
+#[cfg(feature = "global_alloc")]
+use core::sync::atomic::{AtomicUsize, Ordering};
 
-#[link_section = ".heap"] 
-
-
+#[cfg(feature = "allocator_api")]
+use core::alloc::{AllocError, Allocator};
+#[cfg(feature = "allocator_api")]
+use core::ptr::NonNull;
 
 #[cfg(feature = "global_alloc")]
-
 pub struct BumpAllocator {
     heap_start: usize,
     heap_end: usize,
-    next: usize,
+    // The bump pointer is atomic so that concurrent `alloc` calls from different threads
+    // race through a compare-and-swap loop rather than a data-racing `&mut` write. A
+    // `#[global_allocator]` is `Sync` and shared across every thread, so this is the only
+    // sound way to mutate shared state behind `&self`.
+    next: AtomicUsize,
+    // Highest address ever handed out. The static `HEAP` starts zeroed and bump memory
+    // above this mark has never been written by us, so it is still all-zero and
+    // `alloc_zeroed` can skip the memset for any range that lies entirely beyond it.
+    high_water: AtomicUsize,
 }
 
+#[cfg(feature = "global_alloc")]
 impl BumpAllocator {
+    /// Build an allocator over the region `[heap_start, heap_start + heap_size)`.
+    ///
+    /// # Safety
+    /// The caller must guarantee that region is valid, writable, and lives for as long as
+    /// the allocator hands out pointers into it. The region must also start out all-zero:
+    /// `alloc_zeroed` does not clear bytes that have never been handed out.
     pub const unsafe fn new(heap_start: usize, heap_size: usize) -> Self {
         BumpAllocator {
             heap_start,
             heap_end: heap_start + heap_size,
-            next: heap_start,
+            next: AtomicUsize::new(heap_start),
+            high_water: AtomicUsize::new(heap_start),
         }
     }
 
-    unsafe fn alloc(&mut self, layout: Layout) -> *mut u8 {
-        let alloc_start = align_up(self.next, layout.align());
-        let alloc_end = alloc_start + layout.size();
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let mut next = self.next.load(Ordering::Relaxed);
+        loop {
+            let alloc_start = align_up(next, layout.align());
+            let alloc_end = alloc_start + layout.size();
 
+            if alloc_end > self.heap_end {
+                return core::ptr::null_mut();
+            }
 
-        if alloc_end > self.heap_end {
-            core::ptr::null_mut()
-        } else {
-            self.next = alloc_end;
-            alloc_start as *mut u8
+            match self.next.compare_exchange_weak(
+                next,
+                alloc_end,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    self.high_water.fetch_max(alloc_end, Ordering::Relaxed);
+                    return alloc_start as *mut u8;
+                }
+                Err(current) => next = current,
+            }
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, old_layout: Layout, new_size: usize) -> *mut u8 {
+        let alloc_start = ptr as usize;
+        let old_end = alloc_start + old_layout.size();
+        let new_end = alloc_start + new_size;
+
+        // If `ptr` is still the most recent allocation, grow or shrink it in place in O(1)
+        // by just moving the bump pointer: no copy, same address. The CAS fails (and we
+        // fall through to alloc+copy) if another thread bumped `next` in the meantime.
+        if new_end <= self.heap_end
+            && self
+                .next
+                .compare_exchange(old_end, new_end, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            self.high_water.fetch_max(new_end, Ordering::Relaxed);
+            return ptr;
+        }
+
+        // Otherwise fall back to allocate-and-copy, matching the default `realloc`.
+        let new_layout = Layout::from_size_align_unchecked(new_size, old_layout.align());
+        let new_ptr = self.alloc(new_layout);
+        if !new_ptr.is_null() {
+            core::ptr::copy_nonoverlapping(ptr, new_ptr, old_layout.size().min(new_size));
+        }
+        new_ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let prev_high_water = self.high_water.load(Ordering::Relaxed);
+        let ptr = self.alloc(layout);
+        // Bytes at or above the previous high-water mark have never been handed out and
+        // are therefore still zero from the static's initializer, so the memset the
+        // default `alloc_zeroed` would perform is pure overhead.
+        if !ptr.is_null() && (ptr as usize) < prev_high_water {
+            core::ptr::write_bytes(ptr, 0, layout.size());
+        }
+        ptr
+    }
+
+    /// Free the whole region at once by rewinding the bump pointer to the start.
+    ///
+    /// # Safety
+    /// Every pointer previously handed out is invalidated; the caller must ensure nothing
+    /// still references bump-allocated memory.
+    pub unsafe fn reset(&self) {
+        self.next.store(self.heap_start, Ordering::Relaxed);
+    }
+
+    /// Record the current bump position so it can later be rolled back to with
+    /// [`reset_to`](Self::reset_to), giving stack-discipline scratch arenas.
+    pub fn mark(&self) -> usize {
+        self.next.load(Ordering::Relaxed)
+    }
+
+    /// Roll the bump pointer back to a checkpoint taken by [`mark`](Self::mark).
+    ///
+    /// # Safety
+    /// Everything allocated after `mark` was taken is invalidated; the caller must ensure
+    /// none of it is still referenced.
+    pub unsafe fn reset_to(&self, mark: usize) {
+        self.next.store(mark, Ordering::Relaxed);
+    }
+}
+
+/// RAII scratch scope: captures a [`mark`](BumpAllocator::mark) on creation and rolls the
+/// bump pointer back to it on drop, so a burst of temporaries is reclaimed at the end of
+/// the block. Nest these to match known phase boundaries in a `no_std` workload.
+#[cfg(feature = "global_alloc")]
+pub struct Scope<'a> {
+    allocator: &'a BumpAllocator,
+    mark: usize,
+}
+
+#[cfg(feature = "global_alloc")]
+impl<'a> Scope<'a> {
+    /// Capture the current bump position; it is restored when the guard drops.
+    ///
+    /// # Safety
+    /// Dropping the guard invalidates everything allocated since it was created. The
+    /// caller must ensure nothing allocated within the scope — including collections
+    /// built with `Vec::new_in(&arena)`, which borrow the allocator rather than the
+    /// guard — outlives it, or the restored bump pointer will hand the same memory out
+    /// again, causing a use-after-free.
+    pub unsafe fn new(allocator: &'a BumpAllocator) -> Self {
+        Scope {
+            mark: allocator.mark(),
+            allocator,
         }
     }
 }
 
+#[cfg(feature = "global_alloc")]
+impl Drop for Scope<'_> {
+    fn drop(&mut self) {
+        // Upheld by the caller of the `unsafe` `Scope::new`: nothing allocated in the
+        // scope still references this memory.
+        unsafe { self.allocator.reset_to(self.mark) }
+    }
+}
 
+
+#[cfg(feature = "global_alloc")]
 const fn align_up(addr: usize, align: usize) -> usize {
     (addr + align - 1) & !(align - 1)
 }
 
 
+// A free block, stored *inside* the block it describes: an intrusive node so the free
+// list costs no extra memory. Freed blocks are always at least `size_of::<FreeNode>()`
+// bytes, which is what makes that safe.
+#[cfg(feature = "global_alloc")]
+struct FreeNode {
+    size: usize,
+    next: Option<core::ptr::NonNull<FreeNode>>,
+}
+
+// A first-fit free-list allocator that actually reclaims memory, unlike `BumpAllocator`
+// whose `dealloc` is a no-op. Register whichever one fits the workload: `BumpAllocator`
+// for throwaway arenas, `FreeListAllocator` when long-running allocation churn would
+// otherwise exhaust the fixed heap.
+#[cfg(feature = "global_alloc")]
+pub struct FreeListAllocator {
+    heap_start: usize,
+    heap_end: usize,
+    // Address-sorted list head, guarded by `lock`. `UnsafeCell` because a
+    // `#[global_allocator]` hands out allocations behind `&self`.
+    head: core::cell::UnsafeCell<Option<core::ptr::NonNull<FreeNode>>>,
+    initialized: AtomicUsize,
+    lock: core::sync::atomic::AtomicBool,
+}
+
+// Safe because every access to `head` is serialized by `lock`.
+#[cfg(feature = "global_alloc")]
+unsafe impl Sync for FreeListAllocator {}
+
+#[cfg(feature = "global_alloc")]
+impl FreeListAllocator {
+    /// Build an allocator over the region `[heap_start, heap_start + heap_size)`.
+    ///
+    /// # Safety
+    /// The caller must guarantee that region is valid, writable, and lives for as long as
+    /// the allocator hands out pointers into it.
+    pub const unsafe fn new(heap_start: usize, heap_size: usize) -> Self {
+        FreeListAllocator {
+            heap_start,
+            heap_end: heap_start + heap_size,
+            head: core::cell::UnsafeCell::new(None),
+            initialized: AtomicUsize::new(0),
+            lock: core::sync::atomic::AtomicBool::new(false),
+        }
+    }
+
+    // Round a requested layout up to something a freed block can represent: at least one
+    // `FreeNode` big and at least `FreeNode`-aligned.
+    fn adjust_layout(layout: Layout) -> Layout {
+        let layout = layout
+            .align_to(core::mem::align_of::<FreeNode>())
+            .unwrap()
+            .pad_to_align();
+        let size = layout.size().max(core::mem::size_of::<FreeNode>());
+        Layout::from_size_align(size, layout.align()).unwrap()
+    }
+
+    // Acquire the spinlock, run `f` with exclusive access to the list head, release.
+    unsafe fn with_list<T>(&self, f: impl FnOnce(&mut Option<core::ptr::NonNull<FreeNode>>) -> T) -> T {
+        while self
+            .lock
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        if self.initialized.swap(1, Ordering::Relaxed) == 0 {
+            let head = &mut *self.head.get();
+            // The backing `static mut HEAP` has alignment 1, so `heap_start` is not
+            // guaranteed to be `FreeNode`-aligned; writing a node there would be UB.
+            // Align the region up (dropping the unusable prefix) so every node — here and
+            // at every subsequent split — is properly aligned.
+            let start = align_up(self.heap_start, core::mem::align_of::<FreeNode>());
+            if start < self.heap_end
+                && self.heap_end - start >= core::mem::size_of::<FreeNode>()
+            {
+                self.add_free_region(head, start, self.heap_end - start);
+            }
+        }
+        let result = f(&mut *self.head.get());
+        self.lock.store(false, Ordering::Release);
+        result
+    }
+
+    // Insert a freed block into the address-sorted list, coalescing it with any
+    // physically adjacent free neighbours so fragmentation does not accumulate.
+    unsafe fn add_free_region(
+        &self,
+        head: &mut Option<core::ptr::NonNull<FreeNode>>,
+        addr: usize,
+        mut size: usize,
+    ) {
+        // Walk to the first node whose address is past ours, tracking the predecessor.
+        let mut prev: Option<core::ptr::NonNull<FreeNode>> = None;
+        let mut cur = *head;
+        while let Some(node) = cur {
+            if node.as_ptr() as usize > addr {
+                break;
+            }
+            prev = cur;
+            cur = node.as_ref().next;
+        }
+
+        // Coalesce forward: if this block ends exactly where the next one begins, absorb it.
+        if let Some(next) = cur {
+            if addr + size == next.as_ptr() as usize {
+                size += next.as_ref().size;
+                cur = next.as_ref().next;
+            }
+        }
+
+        // Coalesce backward: if the predecessor ends exactly where we begin, extend it.
+        if let Some(mut p) = prev {
+            let p_ref = p.as_mut();
+            if p.as_ptr() as usize + p_ref.size == addr {
+                p_ref.size += size;
+                p_ref.next = cur;
+                return;
+            }
+        }
+
+        // No backward merge: write our node in place and link it in.
+        let node = addr as *mut FreeNode;
+        node.write(FreeNode { size, next: cur });
+        let node = core::ptr::NonNull::new_unchecked(node);
+        match prev {
+            Some(mut p) => p.as_mut().next = Some(node),
+            None => *head = Some(node),
+        }
+    }
+
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let layout = Self::adjust_layout(layout);
+        self.with_list(|head| {
+            // First-fit: find a region the allocation fits inside once aligned.
+            let mut prev: Option<core::ptr::NonNull<FreeNode>> = None;
+            let mut cur = *head;
+            while let Some(node) = cur {
+                let region_start = node.as_ptr() as usize;
+                let region_end = region_start + node.as_ref().size;
+                let alloc_start = align_up(region_start, layout.align());
+                let alloc_end = alloc_start + layout.size();
+
+                if alloc_end <= region_end {
+                    let node_size = core::mem::size_of::<FreeNode>();
+                    let front = alloc_start - region_start;
+                    let tail = region_end - alloc_end;
+                    let next = node.as_ref().next;
+
+                    // Unlink this region, then give back the representable remainders.
+                    match prev {
+                        Some(mut p) => p.as_mut().next = next,
+                        None => *head = next,
+                    }
+                    // Alignment padding in front of the allocation: reclaim it when it is
+                    // large enough to hold a node. A sub-node sliver is unrepresentable
+                    // and left out rather than leaked as a corrupt node.
+                    if front >= node_size {
+                        self.add_free_region(head, region_start, front);
+                    }
+                    // Trailing remainder: split it off when representable, otherwise fold
+                    // the sliver into the returned allocation as slack (it fits).
+                    if tail >= node_size {
+                        self.add_free_region(head, alloc_end, tail);
+                    }
+                    return alloc_start as *mut u8;
+                }
+
+                prev = cur;
+                cur = node.as_ref().next;
+            }
+            core::ptr::null_mut()
+        })
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let layout = Self::adjust_layout(layout);
+        self.with_list(|head| {
+            self.add_free_region(head, ptr as usize, layout.size());
+        });
+    }
+}
+
+#[cfg(feature = "global_alloc")]
+unsafe impl GlobalAlloc for FreeListAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        FreeListAllocator::alloc(self, layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        FreeListAllocator::dealloc(self, ptr, layout)
+    }
+}
+
+
+#[cfg(feature = "global_alloc")]
 unsafe impl GlobalAlloc for BumpAllocator {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        let allocator = &mut *(self as *const _ as *mut BumpAllocator);
-        allocator.alloc(layout)
+        BumpAllocator::alloc(self, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        BumpAllocator::alloc_zeroed(self, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        BumpAllocator::realloc(self, ptr, layout, new_size)
     }
 
     unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {
     }
 }
 
-const HEAP_SIZE: usize = 1024 * 1024; 
+// A `#[global_allocator]` can only ever be installed once, but `core::alloc::Allocator`
+// lets a caller hand an allocator instance straight to a collection via `Vec::new_in`
+// / `Box::new_in`. We implement it for `&BumpAllocator` because `Allocator` requires the
+// allocator to be shareable, and a shared reference is the cheap `Copy` handle that lets
+// several independent bump regions coexist (e.g. one arena per request).
+#[cfg(feature = "allocator_api")]
+unsafe impl Allocator for &BumpAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = unsafe { GlobalAlloc::alloc(*self, layout) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Ok(NonNull::slice_from_raw_parts(ptr, layout.size())),
+            None => Err(AllocError),
+        }
+    }
+
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        bump_realloc(self, ptr, old_layout, new_layout)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        bump_realloc(self, ptr, old_layout, new_layout)
+    }
+}
+
+// `GlobalAlloc::realloc` can only preserve `old_layout.align()` — it never changes
+// alignment. When `Allocator::grow`/`shrink` ask for a *stricter* alignment, the in-place
+// path would hand back under-aligned memory (UB), so fall back to a fresh, correctly
+// aligned allocation and copy instead.
+#[cfg(feature = "allocator_api")]
+unsafe fn bump_realloc(
+    allocator: &BumpAllocator,
+    ptr: NonNull<u8>,
+    old_layout: Layout,
+    new_layout: Layout,
+) -> Result<NonNull<[u8]>, AllocError> {
+    let new_ptr = if new_layout.align() <= old_layout.align() {
+        GlobalAlloc::realloc(allocator, ptr.as_ptr(), old_layout, new_layout.size())
+    } else {
+        let fresh = GlobalAlloc::alloc(allocator, new_layout);
+        if !fresh.is_null() {
+            core::ptr::copy_nonoverlapping(
+                ptr.as_ptr(),
+                fresh,
+                old_layout.size().min(new_layout.size()),
+            );
+        }
+        fresh
+    };
+    match NonNull::new(new_ptr) {
+        Some(new_ptr) => Ok(NonNull::slice_from_raw_parts(new_ptr, new_layout.size())),
+        None => Err(AllocError),
+    }
+}
+
+/// Wraps a [`BumpAllocator`] with a backing `GlobalAlloc` to fall back on. The bump
+/// allocator serves the common case in O(1); when the fixed heap is exhausted and it
+/// would return null, the request is delegated to `fallback` (e.g. `std::alloc::System`,
+/// or a [`FreeListAllocator`]) instead of aborting the program on OOM.
+#[cfg(feature = "global_alloc")]
+pub struct Fallback<A: GlobalAlloc> {
+    primary: BumpAllocator,
+    fallback: A,
+}
 
+#[cfg(feature = "global_alloc")]
+impl<A: GlobalAlloc> Fallback<A> {
+    pub const fn new(primary: BumpAllocator, fallback: A) -> Self {
+        Fallback { primary, fallback }
+    }
+
+    // Does `ptr` live inside the bump heap? That decides which allocator owns it.
+    fn owns(&self, ptr: *mut u8) -> bool {
+        let addr = ptr as usize;
+        addr >= self.primary.heap_start && addr < self.primary.heap_end
+    }
+}
+
+#[cfg(feature = "global_alloc")]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Fallback<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = GlobalAlloc::alloc(&self.primary, layout);
+        if ptr.is_null() {
+            self.fallback.alloc(layout)
+        } else {
+            ptr
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if self.owns(ptr) {
+            GlobalAlloc::dealloc(&self.primary, ptr, layout);
+        } else {
+            self.fallback.dealloc(ptr, layout);
+        }
+    }
+}
+
+#[cfg(feature = "global")]
+const HEAP_SIZE: usize = 1024 * 1024;
+
+#[cfg(feature = "global")]
+#[link_section = ".heap"]
 static mut HEAP: [u8; HEAP_SIZE] = [0; HEAP_SIZE];
 
+// A `&'static` address cannot be turned into an integer inside a `const`/`static`
+// initializer (pointer-to-integer casts are rejected during const eval), so the global
+// slot binds the real heap bounds lazily on first use — the same spinlock-guarded
+// one-shot init pattern `FreeListAllocator` uses.
+#[cfg(feature = "global")]
+struct LazyBump {
+    inner: core::cell::UnsafeCell<core::mem::MaybeUninit<BumpAllocator>>,
+    initialized: AtomicUsize,
+    lock: core::sync::atomic::AtomicBool,
+}
 
-#[global_allocator]
-static GLOBAL_ALLOCATOR: BumpAllocator = unsafe {
-    BumpAllocator::new(HEAP.as_ptr() as usize, HEAP_SIZE)
-};
+#[cfg(feature = "global")]
+unsafe impl Sync for LazyBump {}
+
+#[cfg(feature = "global")]
+impl LazyBump {
+    const fn new() -> Self {
+        LazyBump {
+            inner: core::cell::UnsafeCell::new(core::mem::MaybeUninit::uninit()),
+            initialized: AtomicUsize::new(0),
+            lock: core::sync::atomic::AtomicBool::new(false),
+        }
+    }
+
+    unsafe fn get(&self) -> &BumpAllocator {
+        if self.initialized.load(Ordering::Acquire) == 0 {
+            while self
+                .lock
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                core::hint::spin_loop();
+            }
+            if self.initialized.load(Ordering::Relaxed) == 0 {
+                let start = core::ptr::addr_of!(HEAP) as usize;
+                (*self.inner.get()).write(BumpAllocator::new(start, HEAP_SIZE));
+                self.initialized.store(1, Ordering::Release);
+            }
+            self.lock.store(false, Ordering::Release);
+        }
+        (*self.inner.get()).assume_init_ref()
+    }
+}
+
+#[cfg(feature = "global")]
+unsafe impl GlobalAlloc for LazyBump {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        GlobalAlloc::alloc(self.get(), layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        GlobalAlloc::alloc_zeroed(self.get(), layout)
+    }
 
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        GlobalAlloc::realloc(self.get(), ptr, layout, new_size)
+    }
 
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        GlobalAlloc::dealloc(self.get(), ptr, layout)
+    }
+}
 
+#[cfg(feature = "global")]
+#[global_allocator]
+static GLOBAL_ALLOCATOR: LazyBump = LazyBump::new();
+
+
+#[cfg(feature = "global_alloc")]
+// A demonstration of driving several collections through the registered allocator; the
+// incremental `push`es are kept deliberately to show allocation growth.
+#[allow(clippy::vec_init_then_push)]
 pub fn allocate_example() {
     // Import necessary types from the `alloc` crate.
     use alloc::vec::Vec;
@@ -80,11 +590,12 @@ pub fn allocate_example() {
     numbers.push(2);
     numbers.push(3);
 
-    let boxed_value = Box::new(42);
-    let greeting = String::from("Hello, world!");
-    let boxed_array = Box::new([10, 20, 30, 40, 50]);
+    let _boxed_value = Box::new(42);
+    let _greeting = String::from("Hello, world!");
+    let _boxed_array = Box::new([10, 20, 30, 40, 50]);
 
     #[derive(Debug)]
+    #[allow(dead_code)]
     struct Node {
         value: u32,
         next: Option<Box<Node>>,
@@ -92,6 +603,5 @@ pub fn allocate_example() {
 
     let node3 = Box::new(Node { value: 3, next: None });
     let node2 = Box::new(Node { value: 2, next: Some(node3) });
-    let node1 = Box::new(Node { value: 1, next: Some(node2) });
-
+    let _node1 = Box::new(Node { value: 1, next: Some(node2) });
 }
\ No newline at end of file